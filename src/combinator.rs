@@ -0,0 +1,611 @@
+//! Adapters for combining [`Parse`] implementations into larger parsers.
+//!
+//! These are returned by the combinator methods on [`Parse`] (e.g. [`Parse::map`],
+//! [`Parse::then`]) rather than constructed directly.
+
+use core::marker::PhantomData;
+use core::ops::Range;
+use core::task::Poll;
+
+use crate::{BuildParser, InputLen, Parse};
+
+/// Shorthand for the result of finishing a collection-producing parser: either the collected
+/// items alongside the remaining input, or an error.
+type FinishResult<C, Input, Error> = Result<Poll<(C, Input)>, Error>;
+
+/// Parser returned by [`Parse::map`].
+pub struct Map<P, F> {
+    parser: P,
+    f: Option<F>,
+}
+impl<P, F> Map<P, F> {
+    pub(crate) fn new(parser: P, f: F) -> Self {
+        Map { parser, f: Some(f) }
+    }
+}
+
+impl<Input, P, F, U> Parse<Input> for Map<P, F>
+where
+    Input: Default + PartialEq,
+    P: Parse<Input>,
+    F: FnOnce(P::Output) -> U,
+{
+    type Output = U;
+    type Error = P::Error;
+
+    fn extraneous(&self, input: Input) -> Self::Error {
+        self.parser.extraneous(input)
+    }
+
+    fn insufficient(&self) -> Self::Error {
+        self.parser.insufficient()
+    }
+
+    fn try_parse(&mut self, input: Input) -> Result<Poll<(Self::Output, Input)>, Self::Error> {
+        match self.parser.try_parse(input)? {
+            Poll::Pending => Ok(Poll::Pending),
+            Poll::Ready((output, remaining)) => {
+                let f = self.f.take().expect("Map parser polled after completion");
+                Ok(Poll::Ready((f(output), remaining)))
+            }
+        }
+    }
+}
+
+/// Parser returned by [`Parse::map_err`].
+pub struct MapErr<P, F> {
+    parser: P,
+    f: F,
+}
+impl<P, F> MapErr<P, F> {
+    pub(crate) fn new(parser: P, f: F) -> Self {
+        MapErr { parser, f }
+    }
+}
+
+impl<Input, P, F, E> Parse<Input> for MapErr<P, F>
+where
+    Input: Default + PartialEq,
+    P: Parse<Input>,
+    F: Fn(P::Error) -> E,
+{
+    type Output = P::Output;
+    type Error = E;
+
+    fn extraneous(&self, input: Input) -> Self::Error {
+        (self.f)(self.parser.extraneous(input))
+    }
+
+    fn insufficient(&self) -> Self::Error {
+        (self.f)(self.parser.insufficient())
+    }
+
+    fn try_parse(&mut self, input: Input) -> Result<Poll<(Self::Output, Input)>, Self::Error> {
+        let f = &self.f;
+        self.parser.try_parse(input).map_err(f)
+    }
+}
+
+/// Parser returned by [`Parse::and_then`].
+pub struct AndThen<P, F> {
+    parser: P,
+    f: Option<F>,
+}
+impl<P, F> AndThen<P, F> {
+    pub(crate) fn new(parser: P, f: F) -> Self {
+        AndThen { parser, f: Some(f) }
+    }
+}
+
+impl<Input, P, F, U> Parse<Input> for AndThen<P, F>
+where
+    Input: Default + PartialEq,
+    P: Parse<Input>,
+    F: FnOnce(P::Output) -> Result<U, P::Error>,
+{
+    type Output = U;
+    type Error = P::Error;
+
+    fn extraneous(&self, input: Input) -> Self::Error {
+        self.parser.extraneous(input)
+    }
+
+    fn insufficient(&self) -> Self::Error {
+        self.parser.insufficient()
+    }
+
+    fn try_parse(&mut self, input: Input) -> Result<Poll<(Self::Output, Input)>, Self::Error> {
+        match self.parser.try_parse(input)? {
+            Poll::Pending => Ok(Poll::Pending),
+            Poll::Ready((output, remaining)) => {
+                let f = self
+                    .f
+                    .take()
+                    .expect("AndThen parser polled after completion");
+                Ok(Poll::Ready((f(output)?, remaining)))
+            }
+        }
+    }
+}
+
+/// Internal state for [`Then`], tracking which of the two parsers is currently running.
+enum ThenState<Input, P1, P2>
+where
+    Input: Default + PartialEq,
+    P1: Parse<Input>,
+{
+    /// Still running the first parser; the second is kept alongside, ready to take over.
+    ParsingFirst(P1, P2),
+
+    /// First parser finished with this output; now running the second.
+    ParsingSecond(P1::Output, P2),
+
+    /// Both parsers finished; kept around so `extraneous`/`insufficient` still have a parser to
+    /// delegate to.
+    Done(P2),
+}
+
+/// Parser returned by [`Parse::then`].
+pub struct Then<Input, P1, P2>
+where
+    Input: Default + PartialEq,
+    P1: Parse<Input>,
+{
+    state: Option<ThenState<Input, P1, P2>>,
+}
+impl<Input, P1, P2> Then<Input, P1, P2>
+where
+    Input: Default + PartialEq,
+    P1: Parse<Input>,
+{
+    pub(crate) fn new(first: P1, second: P2) -> Self {
+        Then {
+            state: Some(ThenState::ParsingFirst(first, second)),
+        }
+    }
+}
+
+impl<Input, P1, P2> Parse<Input> for Then<Input, P1, P2>
+where
+    Input: Default + PartialEq,
+    P1: Parse<Input>,
+    P2: Parse<Input, Error = P1::Error>,
+{
+    type Output = (P1::Output, P2::Output);
+    type Error = P1::Error;
+
+    fn extraneous(&self, input: Input) -> Self::Error {
+        match self.state.as_ref().expect("Then parser polled after completion") {
+            ThenState::ParsingFirst(first, _) => first.extraneous(input),
+            ThenState::ParsingSecond(_, second) => second.extraneous(input),
+            ThenState::Done(second) => second.extraneous(input),
+        }
+    }
+
+    fn insufficient(&self) -> Self::Error {
+        match self.state.as_ref().expect("Then parser polled after completion") {
+            ThenState::ParsingFirst(first, _) => first.insufficient(),
+            ThenState::ParsingSecond(_, second) => second.insufficient(),
+            ThenState::Done(second) => second.insufficient(),
+        }
+    }
+
+    fn try_parse(&mut self, input: Input) -> Result<Poll<(Self::Output, Input)>, Self::Error> {
+        match self
+            .state
+            .take()
+            .expect("Then parser polled after completion")
+        {
+            ThenState::ParsingFirst(mut first, second) => match first.try_parse(input)? {
+                Poll::Pending => {
+                    self.state = Some(ThenState::ParsingFirst(first, second));
+                    Ok(Poll::Pending)
+                }
+                Poll::Ready((first_output, remaining)) => {
+                    self.state = Some(ThenState::ParsingSecond(first_output, second));
+                    self.try_parse(remaining)
+                }
+            },
+            ThenState::ParsingSecond(first_output, mut second) => match second.try_parse(input)? {
+                Poll::Pending => {
+                    self.state = Some(ThenState::ParsingSecond(first_output, second));
+                    Ok(Poll::Pending)
+                }
+                Poll::Ready((second_output, remaining)) => {
+                    self.state = Some(ThenState::Done(second));
+                    Ok(Poll::Ready(((first_output, second_output), remaining)))
+                }
+            },
+            ThenState::Done(second) => {
+                self.state = Some(ThenState::Done(second));
+                panic!("Then parser polled after completion")
+            }
+        }
+    }
+}
+
+/// Parser returned by [`Parse::or`].
+///
+/// Because a streaming parser may return [`Poll::Pending`] several times before it errors, `or`
+/// can only guarantee backtracking to `P2` against the most recent chunk handed to [`try_parse`];
+/// earlier chunks that `self` already consumed are gone. Parsers composed with `or` should
+/// therefore be fed complete input (e.g. via [`parse`] or [`parse_only`]) for full backtracking.
+///
+/// [`try_parse`]: Parse::try_parse
+/// [`parse`]: Parse::parse
+/// [`parse_only`]: Parse::parse_only
+pub struct Or<P1, P2> {
+    first: P1,
+    second: P2,
+}
+impl<P1, P2> Or<P1, P2> {
+    pub(crate) fn new(first: P1, second: P2) -> Self {
+        Or { first, second }
+    }
+}
+
+impl<Input, P1, P2> Parse<Input> for Or<P1, P2>
+where
+    Input: Default + PartialEq + Clone,
+    P1: Parse<Input>,
+    P2: Parse<Input, Output = P1::Output, Error = P1::Error>,
+{
+    type Output = P1::Output;
+    type Error = P1::Error;
+
+    fn extraneous(&self, input: Input) -> Self::Error {
+        self.second.extraneous(input)
+    }
+
+    fn insufficient(&self) -> Self::Error {
+        self.second.insufficient()
+    }
+
+    fn try_parse(&mut self, input: Input) -> Result<Poll<(Self::Output, Input)>, Self::Error> {
+        match self.first.try_parse(input.clone()) {
+            Ok(poll) => Ok(poll),
+            Err(_) => self.second.try_parse(input),
+        }
+    }
+}
+
+/// Parser returned by [`BuildParser::many`] and [`BuildParser::many1`].
+pub struct Many<Input, B, C>
+where
+    Input: Default + PartialEq,
+    B: BuildParser<Input>,
+{
+    builder: B,
+    current: B::Parser,
+    items: Vec<<B::Parser as Parse<Input>>::Output>,
+    min: usize,
+    _collection: PhantomData<C>,
+}
+impl<Input, B, C> Many<Input, B, C>
+where
+    Input: Default + PartialEq,
+    B: BuildParser<Input>,
+{
+    pub(crate) fn new(builder: B, min: usize) -> Self {
+        let current = builder.build_parser();
+        Many {
+            builder,
+            current,
+            items: Vec::new(),
+            min,
+            _collection: PhantomData,
+        }
+    }
+
+    /// Collects the accumulated items, failing if fewer than `min` were parsed.
+    fn finish(
+        &mut self,
+        remaining: Input,
+    ) -> FinishResult<C, Input, <B::Parser as Parse<Input>>::Error>
+    where
+        C: FromIterator<<B::Parser as Parse<Input>>::Output>,
+    {
+        if self.items.len() < self.min {
+            return Err(self.current.insufficient());
+        }
+        Ok(Poll::Ready((core::mem::take(&mut self.items).into_iter().collect(), remaining)))
+    }
+}
+
+impl<Input, B, C> Parse<Input> for Many<Input, B, C>
+where
+    Input: Default + PartialEq + Clone,
+    B: BuildParser<Input>,
+    C: FromIterator<<B::Parser as Parse<Input>>::Output>,
+{
+    type Output = C;
+    type Error = <B::Parser as Parse<Input>>::Error;
+
+    fn extraneous(&self, input: Input) -> Self::Error {
+        self.current.extraneous(input)
+    }
+
+    fn insufficient(&self) -> Self::Error {
+        self.current.insufficient()
+    }
+
+    fn try_parse(&mut self, mut input: Input) -> Result<Poll<(Self::Output, Input)>, Self::Error> {
+        loop {
+            let before = input.clone();
+            match self.current.try_parse(input) {
+                Ok(Poll::Pending) => return Ok(Poll::Pending),
+                Ok(Poll::Ready((item, remaining))) => {
+                    let made_progress = remaining != before;
+                    self.items.push(item);
+                    if !made_progress || remaining == Input::default() {
+                        return self.finish(remaining);
+                    }
+                    self.current = self.builder.build_parser();
+                    input = remaining;
+                }
+                Err(_) => return self.finish(before),
+            }
+        }
+    }
+}
+
+/// Internal state for [`SeparatedBy`], tracking whether an element or a separator is expected
+/// next.
+enum SeparatedState<E, S> {
+    /// Expecting an element.
+    Element(E),
+
+    /// Expecting a separator; if one isn't found, the list is complete.
+    Separator(S),
+}
+
+/// Parser returned by [`BuildParser::separated_by`].
+pub struct SeparatedBy<Input, BElem, BSep, C>
+where
+    Input: Default + PartialEq,
+    BElem: BuildParser<Input>,
+    BSep: BuildParser<Input>,
+{
+    elem_builder: BElem,
+    sep_builder: BSep,
+    state: SeparatedState<BElem::Parser, BSep::Parser>,
+    items: Vec<<BElem::Parser as Parse<Input>>::Output>,
+    /// Input just before the current separator attempt, restored if no separator is found.
+    checkpoint: Option<Input>,
+    _collection: PhantomData<C>,
+}
+impl<Input, BElem, BSep, C> SeparatedBy<Input, BElem, BSep, C>
+where
+    Input: Default + PartialEq,
+    BElem: BuildParser<Input>,
+    BSep: BuildParser<Input>,
+{
+    pub(crate) fn new(elem_builder: BElem, sep_builder: BSep) -> Self {
+        let first = elem_builder.build_parser();
+        SeparatedBy {
+            elem_builder,
+            sep_builder,
+            state: SeparatedState::Element(first),
+            items: Vec::new(),
+            checkpoint: None,
+            _collection: PhantomData,
+        }
+    }
+
+    fn finish(
+        &mut self,
+        remaining: Input,
+    ) -> FinishResult<C, Input, <BElem::Parser as Parse<Input>>::Error>
+    where
+        C: FromIterator<<BElem::Parser as Parse<Input>>::Output>,
+    {
+        Ok(Poll::Ready((core::mem::take(&mut self.items).into_iter().collect(), remaining)))
+    }
+}
+
+impl<Input, BElem, BSep, C> Parse<Input> for SeparatedBy<Input, BElem, BSep, C>
+where
+    Input: Default + PartialEq + Clone,
+    BElem: BuildParser<Input>,
+    BSep: BuildParser<Input>,
+    C: FromIterator<<BElem::Parser as Parse<Input>>::Output>,
+{
+    type Output = C;
+    type Error = <BElem::Parser as Parse<Input>>::Error;
+
+    fn extraneous(&self, input: Input) -> Self::Error {
+        match &self.state {
+            SeparatedState::Element(parser) => parser.extraneous(input),
+            SeparatedState::Separator(_) => self.elem_builder.build_parser().extraneous(input),
+        }
+    }
+
+    fn insufficient(&self) -> Self::Error {
+        match &self.state {
+            SeparatedState::Element(parser) => parser.insufficient(),
+            SeparatedState::Separator(_) => self.elem_builder.build_parser().insufficient(),
+        }
+    }
+
+    fn try_parse(&mut self, mut input: Input) -> Result<Poll<(Self::Output, Input)>, Self::Error> {
+        loop {
+            match &mut self.state {
+                SeparatedState::Element(parser) => match parser.try_parse(input)? {
+                    Poll::Pending => return Ok(Poll::Pending),
+                    Poll::Ready((item, remaining)) => {
+                        self.items.push(item);
+                        if remaining == Input::default() {
+                            return self.finish(remaining);
+                        }
+                        self.checkpoint = Some(remaining.clone());
+                        self.state = SeparatedState::Separator(self.sep_builder.build_parser());
+                        input = remaining;
+                    }
+                },
+                SeparatedState::Separator(parser) => match parser.try_parse(input) {
+                    Ok(Poll::Pending) => return Ok(Poll::Pending),
+                    Ok(Poll::Ready((_, remaining))) => {
+                        self.state = SeparatedState::Element(self.elem_builder.build_parser());
+                        input = remaining;
+                    }
+                    Err(_) => {
+                        let checkpoint = self
+                            .checkpoint
+                            .take()
+                            .expect("checkpoint set before entering Separator state");
+                        return self.finish(checkpoint);
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// Parser returned by [`BuildParser::recover_with`].
+pub struct RecoverWith<Input, B1, B2>
+where
+    Input: Default + PartialEq,
+    B1: BuildParser<Input>,
+    B2: BuildParser<Input>,
+{
+    builder: B1,
+    current: B1::Parser,
+    recovery: B2,
+}
+impl<Input, B1, B2> RecoverWith<Input, B1, B2>
+where
+    Input: Default + PartialEq,
+    B1: BuildParser<Input>,
+    B2: BuildParser<Input>,
+{
+    pub(crate) fn new(builder: B1, recovery: B2) -> Self {
+        let current = builder.build_parser();
+        RecoverWith {
+            builder,
+            current,
+            recovery,
+        }
+    }
+}
+
+impl<Input, B1, B2> Parse<Input> for RecoverWith<Input, B1, B2>
+where
+    Input: Default + PartialEq + Clone,
+    B1: BuildParser<Input>,
+    B2: BuildParser<Input>,
+{
+    type Output = <B1::Parser as Parse<Input>>::Output;
+    type Error = <B1::Parser as Parse<Input>>::Error;
+
+    fn extraneous(&self, input: Input) -> Self::Error {
+        self.current.extraneous(input)
+    }
+
+    fn insufficient(&self) -> Self::Error {
+        self.current.insufficient()
+    }
+
+    fn try_parse(&mut self, input: Input) -> Result<Poll<(Self::Output, Input)>, Self::Error> {
+        self.current.try_parse(input)
+    }
+
+    fn try_parse_recover(
+        &mut self,
+        mut input: Input,
+    ) -> (Option<Self::Output>, Vec<Self::Error>, Input) {
+        let mut errors = Vec::new();
+        loop {
+            let snapshot = input.clone();
+            match self.current.try_parse(input) {
+                Ok(Poll::Pending) => return (None, errors, Input::default()),
+                Ok(Poll::Ready((output, remaining))) => return (Some(output), errors, remaining),
+                Err(error) => {
+                    errors.push(error);
+                    match self.recovery.build_parser().try_parse(snapshot) {
+                        Ok(Poll::Ready((_, remaining))) => {
+                            self.current = self.builder.build_parser();
+                            input = remaining;
+                        }
+                        _ => return (None, errors, Input::default()),
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Internal state for [`WithSpan`], tracking the absolute offset consumed so far.
+struct Positioned<Input> {
+    /// Total input consumed by values this parser has already finished producing; the start
+    /// offset of whichever value is currently in progress.
+    completed: usize,
+
+    /// Input consumed so far towards the value currently in progress, across however many
+    /// `try_parse` calls it has taken.
+    pending: usize,
+
+    _input: PhantomData<Input>,
+}
+impl<Input> Default for Positioned<Input> {
+    fn default() -> Self {
+        Positioned {
+            completed: 0,
+            pending: 0,
+            _input: PhantomData,
+        }
+    }
+}
+
+/// Parser returned by [`Parse::with_span`].
+///
+/// The offset each span reports is relative to the input first handed to *this* `WithSpan`, not
+/// to some larger document it may be embedded in. This only lines up with an absolute column in a
+/// larger document when `with_span` wraps the outermost parser; spans from nested or composed
+/// `with_span` calls (e.g. one inside a [`Then`] or [`Many`] built from sub-parsers that are
+/// themselves wrapped) each restart from zero and do not add up.
+pub struct WithSpan<Input, P> {
+    parser: P,
+    position: Positioned<Input>,
+}
+impl<Input, P> WithSpan<Input, P> {
+    pub(crate) fn new(parser: P) -> Self {
+        WithSpan {
+            parser,
+            position: Positioned::default(),
+        }
+    }
+}
+
+impl<Input, P> Parse<Input> for WithSpan<Input, P>
+where
+    Input: Default + PartialEq + InputLen,
+    P: Parse<Input>,
+{
+    type Output = (P::Output, Range<usize>);
+    type Error = P::Error;
+
+    fn extraneous(&self, input: Input) -> Self::Error {
+        self.parser.extraneous(input)
+    }
+
+    fn insufficient(&self) -> Self::Error {
+        self.parser.insufficient()
+    }
+
+    fn try_parse(&mut self, input: Input) -> Result<Poll<(Self::Output, Input)>, Self::Error> {
+        let before = input.input_len();
+        match self.parser.try_parse(input)? {
+            Poll::Pending => {
+                self.position.pending += before;
+                Ok(Poll::Pending)
+            }
+            Poll::Ready((output, remaining)) => {
+                let start = self.position.completed;
+                let end = start + self.position.pending + (before - remaining.input_len());
+                self.position.completed = end;
+                self.position.pending = 0;
+                Ok(Poll::Ready(((output, start..end), remaining)))
+            }
+        }
+    }
+}
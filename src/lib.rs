@@ -1,5 +1,32 @@
 use core::task::Poll;
 
+pub mod combinator;
+
+pub use combinator::{AndThen, Many, Map, MapErr, Or, RecoverWith, SeparatedBy, Then, WithSpan};
+
+/// Reports how much of an input remains, so [`Parse::with_span`] can tell how much was consumed.
+pub trait InputLen {
+    /// Number of elements (e.g. bytes) remaining in this input.
+    fn input_len(&self) -> usize;
+}
+
+impl InputLen for &str {
+    fn input_len(&self) -> usize {
+        self.len()
+    }
+}
+
+impl InputLen for &[u8] {
+    fn input_len(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Shorthand for the result of parsing a single complete value: the output alongside any
+/// remaining input, or an error.
+type ParseOneResult<P, Input> =
+    Result<(<P as Parse<Input>>::Output, Input), <P as Parse<Input>>::Error>;
+
 /// Trait for building parsers.
 ///
 /// See [`Parse`] for more details on parsers. Because parsers can hold intermediate state while
@@ -14,13 +41,7 @@ pub trait BuildParser<Input: Default + PartialEq> {
 
     /// Creates a parser to parse a single value, failing on insufficient input.
     #[inline]
-    fn parse_one(
-        &self,
-        input: Input,
-    ) -> Result<
-        (<Self::Parser as Parse<Input>>::Output, Input),
-        <Self::Parser as Parse<Input>>::Error,
-    > {
+    fn parse_one(&self, input: Input) -> ParseOneResult<Self::Parser, Input> {
         self.build_parser().parse(input)
     }
 
@@ -32,6 +53,56 @@ pub trait BuildParser<Input: Default + PartialEq> {
     ) -> Result<<Self::Parser as Parse<Input>>::Output, <Self::Parser as Parse<Input>>::Error> {
         self.build_parser().parse_only(input)
     }
+
+    /// Repeatedly builds and runs this parser, collecting zero or more results.
+    #[inline]
+    fn many<C>(self) -> Many<Input, Self, C>
+    where
+        Self: Sized,
+        C: FromIterator<<Self::Parser as Parse<Input>>::Output>,
+    {
+        Many::new(self, 0)
+    }
+
+    /// Repeatedly builds and runs this parser, collecting one or more results.
+    #[inline]
+    fn many1<C>(self) -> Many<Input, Self, C>
+    where
+        Self: Sized,
+        C: FromIterator<<Self::Parser as Parse<Input>>::Output>,
+    {
+        Many::new(self, 1)
+    }
+
+    /// Repeatedly builds and runs this parser, alternating with `sep` between each, and
+    /// collecting one or more results.
+    #[inline]
+    fn separated_by<Sep, C>(self, sep: Sep) -> SeparatedBy<Input, Self, Sep, C>
+    where
+        Self: Sized,
+        Sep: BuildParser<Input>,
+        C: FromIterator<<Self::Parser as Parse<Input>>::Output>,
+    {
+        SeparatedBy::new(self, sep)
+    }
+
+    /// On error, runs `recovery` to resynchronize, then rebuilds and resumes this parser.
+    ///
+    /// Like [`many`] and [`separated_by`], this needs a fresh parser after every error, so it's
+    /// provided here rather than on [`Parse`] directly. The combined parser's ordinary
+    /// [`Parse::try_parse`] still stops at the first error; the recovery loop only runs through
+    /// [`Parse::try_parse_recover`].
+    ///
+    /// [`many`]: BuildParser::many
+    /// [`separated_by`]: BuildParser::separated_by
+    #[inline]
+    fn recover_with<P2>(self, recovery: P2) -> RecoverWith<Input, Self, P2>
+    where
+        Self: Sized,
+        P2: BuildParser<Input>,
+    {
+        RecoverWith::new(self, recovery)
+    }
 }
 
 /// Something that can parse an `Input` (usually [`str`] slices) into an `Output`.
@@ -136,4 +207,83 @@ pub trait Parse<Input: Default + PartialEq>: Sized {
             }
         }
     }
+
+    /// Parses a value, recording errors instead of aborting on the first one.
+    ///
+    /// Returns a best-effort output (`None` if nothing could be salvaged), every error
+    /// encountered along the way, and whatever input remains once no more progress can be made.
+    ///
+    /// The default implementation can't skip over a bad region, so it just defers to
+    /// [`try_parse`] and stops at the first error or [`Poll::Pending`]; combinators that know how
+    /// to resynchronize (e.g. [`RecoverWith`]) override this to keep going.
+    ///
+    /// [`try_parse`]: Parse::try_parse
+    fn try_parse_recover(&mut self, input: Input) -> (Option<Self::Output>, Vec<Self::Error>, Input) {
+        match self.try_parse(input) {
+            Ok(Poll::Pending) => (None, Vec::new(), Input::default()),
+            Ok(Poll::Ready((output, remaining))) => (Some(output), Vec::new(), remaining),
+            Err(error) => (None, vec![error], Input::default()),
+        }
+    }
+
+    /// Transforms the output of this parser with `f` once it succeeds.
+    #[inline]
+    fn map<F, U>(self, f: F) -> Map<Self, F>
+    where
+        F: FnOnce(Self::Output) -> U,
+    {
+        Map::new(self, f)
+    }
+
+    /// Transforms the error of this parser with `f`.
+    #[inline]
+    fn map_err<F, E>(self, f: F) -> MapErr<Self, F>
+    where
+        F: Fn(Self::Error) -> E,
+    {
+        MapErr::new(self, f)
+    }
+
+    /// Transforms the output of this parser with `f` once it succeeds, allowing `f` to fail.
+    #[inline]
+    fn and_then<F, U>(self, f: F) -> AndThen<Self, F>
+    where
+        F: FnOnce(Self::Output) -> Result<U, Self::Error>,
+    {
+        AndThen::new(self, f)
+    }
+
+    /// Runs this parser, then feeds its remaining input into `second`, yielding both outputs.
+    #[inline]
+    fn then<P2>(self, second: P2) -> Then<Input, Self, P2>
+    where
+        P2: Parse<Input, Error = Self::Error>,
+    {
+        Then::new(self, second)
+    }
+
+    /// Tries this parser, falling back to restarting `second` on the original input on error.
+    #[inline]
+    fn or<P2>(self, second: P2) -> Or<Self, P2>
+    where
+        Input: Clone,
+        P2: Parse<Input, Output = Self::Output, Error = Self::Error>,
+    {
+        Or::new(self, second)
+    }
+
+    /// Pairs the output with the `start..end` range of input consumed to produce it.
+    ///
+    /// The range is measured in [`InputLen`] units (e.g. bytes) from the start of the first input
+    /// ever handed to this parser, and accumulates correctly across multiple [`try_parse`] calls
+    /// on streamed input.
+    ///
+    /// [`try_parse`]: Parse::try_parse
+    #[inline]
+    fn with_span(self) -> WithSpan<Input, Self>
+    where
+        Input: InputLen,
+    {
+        WithSpan::new(self)
+    }
 }
@@ -0,0 +1,72 @@
+use core::task::Poll;
+use parse_trait::Parse;
+
+struct Eq(char);
+impl<'a> Parse<&'a str> for Eq {
+    type Output = char;
+    type Error = String;
+    fn extraneous(&self, _input: &'a str) -> Self::Error {
+        "extraneous".into()
+    }
+    fn insufficient(&self) -> Self::Error {
+        "insufficient".into()
+    }
+    fn try_parse(&mut self, input: &'a str) -> Result<Poll<(char, &'a str)>, String> {
+        if input.is_empty() {
+            return Ok(Poll::Pending);
+        }
+        let mut chars = input.chars();
+        let c = chars.next().unwrap();
+        if c == self.0 {
+            Ok(Poll::Ready((c, chars.as_str())))
+        } else {
+            Err(format!("expected {}", self.0))
+        }
+    }
+}
+
+#[test]
+fn map_works() {
+    let mut p = Eq('a').map(|c| c.to_ascii_uppercase());
+    assert_eq!(p.parse_only("a"), Ok('A'));
+}
+
+#[test]
+fn then_works() {
+    let mut p = Eq('a').then(Eq('b'));
+    assert_eq!(p.parse_only("ab"), Ok(('a', 'b')));
+}
+
+#[test]
+fn then_pending_across_calls() {
+    let mut p = Eq('a').then(Eq('b'));
+    assert_eq!(p.try_parse(""), Ok(Poll::Pending));
+    assert_eq!(p.try_parse("a"), Ok(Poll::Pending));
+    assert_eq!(p.try_parse("b"), Ok(Poll::Ready((('a', 'b'), ""))));
+}
+
+#[test]
+fn then_extraneous_after_completion() {
+    let mut p = Eq('a').then(Eq('b'));
+    assert_eq!(p.parse_only("abc"), Err("extraneous".into()));
+}
+
+#[test]
+fn or_works() {
+    let mut p = Eq('a').or(Eq('b'));
+    assert_eq!(p.parse_only("b"), Ok('b'));
+    let mut p2 = Eq('a').or(Eq('b'));
+    assert_eq!(p2.parse_only("a"), Ok('a'));
+}
+
+#[test]
+fn and_then_works() {
+    let mut p = Eq('a').and_then(|c| Ok(c as u32));
+    assert_eq!(p.parse_only("a"), Ok(97));
+}
+
+#[test]
+fn map_err_works() {
+    let mut p = Eq('a').map_err(|e| format!("wrapped: {e}"));
+    assert_eq!(p.parse_only("z"), Err("wrapped: expected a".to_string()));
+}
@@ -1,14 +1,23 @@
 use core::{mem, task::Poll};
-use num_traits::{CheckedAdd, CheckedMul};
+use num_traits::{CheckedAdd, CheckedMul, CheckedNeg};
 use parse_trait::{BuildParser, Parse};
 
 /// Parses an integer from a string.
-pub struct ParseInt<T: Default + CheckedAdd + CheckedMul> {
+pub struct ParseInt<T: Default + CheckedAdd + CheckedMul + PartialOrd> {
     /// Current parsed value.
     val: T,
 
     /// Radix being parsed.
     radix: T,
+
+    /// Number of digits consumed so far.
+    count: usize,
+
+    /// Maximum number of digits to consume before cutting off the value as complete.
+    max_digits: Option<usize>,
+
+    /// Maximum value (inclusive) to consume before cutting off the value as complete.
+    max_value: Option<T>,
 }
 
 /// Error parsing an integer.
@@ -25,7 +34,7 @@ pub enum ParseIntError {
 }
 
 /// Generic trait to make implementing easier (a libstd version would just use macros, probably).
-pub trait FromRadix: Default + CheckedAdd + CheckedMul {
+pub trait FromRadix: Default + CheckedAdd + CheckedMul + PartialOrd {
     /// Equivalent to 2 <= radix && radix <= 36.
     fn is_valid_radix(radix: &Self) -> bool;
 
@@ -34,6 +43,21 @@ pub trait FromRadix: Default + CheckedAdd + CheckedMul {
 
     /// Equivalent to `char::to_digit`.
     fn from_digit_radix(c: char, radix: &Self) -> Self;
+
+    /// Byte-slice equivalent of [`is_digit_radix`], for parsing raw (ASCII) digit bytes without a
+    /// UTF-8 validation pass.
+    ///
+    /// [`is_digit_radix`]: FromRadix::is_digit_radix
+    fn is_digit_byte(b: u8, radix: &Self) -> bool {
+        Self::is_digit_radix(b as char, radix)
+    }
+
+    /// Byte-slice equivalent of [`from_digit_radix`].
+    ///
+    /// [`from_digit_radix`]: FromRadix::from_digit_radix
+    fn from_digit_byte(b: u8, radix: &Self) -> Self {
+        Self::from_digit_radix(b as char, radix)
+    }
 }
 impl FromRadix for u32 {
     fn is_valid_radix(radix: &u32) -> bool {
@@ -46,6 +70,21 @@ impl FromRadix for u32 {
         c.to_digit(*radix).unwrap()
     }
 }
+impl FromRadix for i32 {
+    fn is_valid_radix(radix: &i32) -> bool {
+        2 <= *radix && *radix <= 36
+    }
+    fn is_digit_radix(c: char, radix: &Self) -> bool {
+        c.is_digit(*radix as u32)
+    }
+    fn from_digit_radix(c: char, radix: &Self) -> Self {
+        c.to_digit(*radix as u32).unwrap() as i32
+    }
+}
+
+/// Extension of [`FromRadix`] for types that can represent negative values.
+pub trait FromRadixSigned: FromRadix + CheckedNeg {}
+impl<T: FromRadix + CheckedNeg> FromRadixSigned for T {}
 
 impl<'a, T: FromRadix> Parse<&'a str> for ParseInt<T> {
     type Output = T;
@@ -68,34 +107,104 @@ impl<'a, T: FromRadix> Parse<&'a str> for ParseInt<T> {
         ParseIntError::Empty
     }
 
-    fn try_parse(
-        &mut self,
-        mut input: &'a str,
-    ) -> Result<Poll<(Self::Output, &'a str)>, Self::Error> {
-        let remaining;
-        if let Some(pos) = input.find(|c: char| !T::is_digit_radix(c, &self.radix)) {
-            (input, remaining) = input.split_at(pos);
-            if input.is_empty() {
-                return Err(ParseIntError::InvalidChar(
-                    remaining.chars().next().unwrap(),
-                ));
+    fn try_parse(&mut self, input: &'a str) -> Result<Poll<(Self::Output, &'a str)>, Self::Error> {
+        let (digits, remaining) =
+            if let Some(pos) = input.find(|c: char| !T::is_digit_radix(c, &self.radix)) {
+                let (digits, remaining) = input.split_at(pos);
+                if digits.is_empty() {
+                    return Err(ParseIntError::InvalidChar(
+                        remaining.chars().next().unwrap(),
+                    ));
+                }
+                (digits, remaining)
+            } else if input.is_empty() {
+                return Ok(Poll::Pending);
+            } else {
+                (input, "")
+            };
+
+        for (i, c) in digits.char_indices() {
+            if self.max_digits.is_some_and(|max_digits| self.count >= max_digits) {
+                return Ok(Poll::Ready((mem::take(&mut self.val), &input[i..])));
             }
+
+            let digit = T::from_digit_radix(c, &self.radix);
+            let next_val = self
+                .val
+                .checked_mul(&self.radix)
+                .and_then(|val| val.checked_add(&digit))
+                .ok_or(ParseIntError::Overflow)?;
+
+            if let Some(max_value) = &self.max_value {
+                if next_val > *max_value {
+                    return Ok(Poll::Ready((mem::take(&mut self.val), &input[i..])));
+                }
+            }
+
+            self.val = next_val;
+            self.count += 1;
+        }
+
+        Ok(Poll::Ready((mem::take(&mut self.val), remaining)))
+    }
+}
+
+impl<'a, T: FromRadix> Parse<&'a [u8]> for ParseInt<T> {
+    type Output = T;
+    type Error = ParseIntError;
+
+    fn extraneous(&self, input: &'a [u8]) -> Self::Error {
+        match input.first() {
+            None => ParseIntError::Empty,
+            Some(&b) => {
+                if T::is_digit_byte(b, &self.radix) {
+                    ParseIntError::Overflow
+                } else {
+                    ParseIntError::InvalidChar(b as char)
+                }
+            }
+        }
+    }
+
+    fn insufficient(&self) -> Self::Error {
+        ParseIntError::Empty
+    }
+
+    fn try_parse(&mut self, input: &'a [u8]) -> Result<Poll<(Self::Output, &'a [u8])>, Self::Error> {
+        let (digits, remaining) = if let Some(pos) =
+            input.iter().position(|&b| !T::is_digit_byte(b, &self.radix))
+        {
+            let (digits, remaining) = input.split_at(pos);
+            if digits.is_empty() {
+                return Err(ParseIntError::InvalidChar(remaining[0] as char));
+            }
+            (digits, remaining)
         } else if input.is_empty() {
             return Ok(Poll::Pending);
         } else {
-            remaining = "";
-        }
+            (input, &input[input.len()..])
+        };
+
+        for (i, &b) in digits.iter().enumerate() {
+            if self.max_digits.is_some_and(|max_digits| self.count >= max_digits) {
+                return Ok(Poll::Ready((mem::take(&mut self.val), &input[i..])));
+            }
 
-        for c in input.chars() {
-            self.val = self
+            let digit = T::from_digit_byte(b, &self.radix);
+            let next_val = self
                 .val
                 .checked_mul(&self.radix)
+                .and_then(|val| val.checked_add(&digit))
                 .ok_or(ParseIntError::Overflow)?;
-            let digit = T::from_digit_radix(c, &self.radix);
-            self.val = self
-                .val
-                .checked_add(&digit)
-                .ok_or(ParseIntError::Overflow)?;
+
+            if let Some(max_value) = &self.max_value {
+                if next_val > *max_value {
+                    return Ok(Poll::Ready((mem::take(&mut self.val), &input[i..])));
+                }
+            }
+
+            self.val = next_val;
+            self.count += 1;
         }
 
         Ok(Poll::Ready((mem::take(&mut self.val), remaining)))
@@ -103,20 +212,341 @@ impl<'a, T: FromRadix> Parse<&'a str> for ParseInt<T> {
 }
 
 /// Would likely be returned by some `T::parse_radix` method.
-pub struct ParseRadix<T>(T);
+#[derive(Clone)]
+pub struct ParseRadix<T> {
+    /// Radix being parsed.
+    radix: T,
+
+    /// Maximum number of digits to consume before cutting off the value as complete.
+    max_digits: Option<usize>,
+
+    /// Maximum value (inclusive) to consume before cutting off the value as complete.
+    max_value: Option<T>,
+}
+
+impl<T> ParseRadix<T> {
+    /// Creates a builder for a parser of integers in the given `radix`.
+    pub fn new(radix: T) -> Self {
+        ParseRadix {
+            radix,
+            max_digits: None,
+            max_value: None,
+        }
+    }
+
+    /// Limits the parser to at most `max_digits` digits, cutting off the value as complete once
+    /// reached and returning the rest of the digit run as remaining input rather than erroring.
+    pub fn max_digits(mut self, max_digits: usize) -> Self {
+        self.max_digits = Some(max_digits);
+        self
+    }
+
+    /// Limits the parsed value to at most `max_value` (inclusive), cutting off the value as
+    /// complete once appending another digit would exceed it.
+    pub fn max_value(mut self, max_value: T) -> Self {
+        self.max_value = Some(max_value);
+        self
+    }
+
+    /// Adds support for an optional leading `+`/`-` sign, parsing a signed integer.
+    pub fn signed(self) -> ParseSignedRadix<T> {
+        ParseSignedRadix(self)
+    }
+
+    /// Detects a leading `0x`/`0o`/`0b` prefix, falling back to this radix when absent.
+    pub fn auto_radix(self) -> ParseAutoRadix<T> {
+        ParseAutoRadix(self)
+    }
+
+    /// Returns a copy of this builder with a different radix, keeping the same limits.
+    fn with_radix(&self, radix: T) -> Self
+    where
+        T: Clone,
+    {
+        ParseRadix {
+            radix,
+            max_digits: self.max_digits,
+            max_value: self.max_value.clone(),
+        }
+    }
+}
+
 impl<'a, T: Clone + FromRadix> BuildParser<&'a str> for ParseRadix<T> {
     type Parser = ParseInt<T>;
     fn build_parser(&self) -> ParseInt<T> {
-        let radix = self.0.clone();
         ParseInt {
             val: T::default(),
-            radix,
+            radix: self.radix.clone(),
+            count: 0,
+            max_digits: self.max_digits,
+            max_value: self.max_value.clone(),
+        }
+    }
+}
+
+impl<'a, T: Clone + FromRadix> BuildParser<&'a [u8]> for ParseRadix<T> {
+    type Parser = ParseInt<T>;
+    fn build_parser(&self) -> ParseInt<T> {
+        ParseInt {
+            val: T::default(),
+            radix: self.radix.clone(),
+            count: 0,
+            max_digits: self.max_digits,
+            max_value: self.max_value.clone(),
+        }
+    }
+}
+
+/// Parses a signed integer, with an optional leading `+` or `-`.
+pub struct ParseSigned<T: FromRadixSigned> {
+    /// `None` until the (possibly absent) sign has been read; `Some(true)` for a `-` sign.
+    negative: Option<bool>,
+
+    /// Parser for the magnitude, run once the sign has been determined.
+    magnitude: ParseInt<T>,
+}
+
+/// Builder for [`ParseSigned`], returned by [`ParseRadix::signed`].
+pub struct ParseSignedRadix<T>(ParseRadix<T>);
+
+impl<'a, T: Clone + FromRadixSigned> BuildParser<&'a str> for ParseSignedRadix<T> {
+    type Parser = ParseSigned<T>;
+    fn build_parser(&self) -> ParseSigned<T> {
+        ParseSigned {
+            negative: None,
+            magnitude: BuildParser::<&'a str>::build_parser(&self.0),
         }
     }
 }
 
+impl<'a, T: FromRadixSigned> Parse<&'a str> for ParseSigned<T> {
+    type Output = T;
+    type Error = ParseIntError;
+
+    fn extraneous(&self, input: &'a str) -> Self::Error {
+        self.magnitude.extraneous(input)
+    }
+
+    fn insufficient(&self) -> Self::Error {
+        ParseIntError::Empty
+    }
+
+    fn try_parse(&mut self, input: &'a str) -> Result<Poll<(Self::Output, &'a str)>, Self::Error> {
+        let input = if self.negative.is_none() {
+            let mut chars = input.chars();
+            match chars.next() {
+                None => return Ok(Poll::Pending),
+                Some('-') => {
+                    self.negative = Some(true);
+                    chars.as_str()
+                }
+                Some('+') => {
+                    self.negative = Some(false);
+                    chars.as_str()
+                }
+                Some(_) => {
+                    self.negative = Some(false);
+                    input
+                }
+            }
+        } else {
+            input
+        };
+
+        match self.magnitude.try_parse(input)? {
+            Poll::Pending => Ok(Poll::Pending),
+            Poll::Ready((val, remaining)) => {
+                let val = if self.negative == Some(true) {
+                    val.checked_neg().ok_or(ParseIntError::Overflow)?
+                } else {
+                    val
+                };
+                Ok(Poll::Ready((val, remaining)))
+            }
+        }
+    }
+}
+
+/// Internal state for [`ParseIntAutoRadix`], tracking whether the radix prefix has been read.
+enum AutoRadixState<T: FromRadix> {
+    /// Still looking for a radix prefix; falls back to this builder's default radix.
+    Prefix(ParseRadix<T>),
+
+    /// Prefix resolved (or absent); parsing the magnitude.
+    Magnitude(ParseInt<T>),
+}
+
+/// Parser returned by [`ParseAutoRadix`], detecting a `0x`/`0o`/`0b` prefix.
+///
+/// A lone `0` with nothing after it resolves immediately to the default-radix value `0`, rather
+/// than waiting indefinitely for a prefix letter that may never come; streaming callers that do
+/// follow it with `x`/`o`/`b` in a later chunk will see that letter rejected as extraneous.
+pub struct ParseIntAutoRadix<T: FromRadix> {
+    state: AutoRadixState<T>,
+}
+
+/// Builder for [`ParseIntAutoRadix`], returned by [`ParseRadix::auto_radix`].
+pub struct ParseAutoRadix<T>(ParseRadix<T>);
+
+impl<'a, T: Clone + FromRadix + From<u8>> BuildParser<&'a str> for ParseAutoRadix<T> {
+    type Parser = ParseIntAutoRadix<T>;
+    fn build_parser(&self) -> ParseIntAutoRadix<T> {
+        ParseIntAutoRadix {
+            state: AutoRadixState::Prefix(self.0.clone()),
+        }
+    }
+}
+
+impl<'a, T: Clone + FromRadix + From<u8>> Parse<&'a str> for ParseIntAutoRadix<T> {
+    type Output = T;
+    type Error = ParseIntError;
+
+    fn extraneous(&self, input: &'a str) -> Self::Error {
+        match &self.state {
+            AutoRadixState::Prefix(builder) => {
+                BuildParser::<&'a str>::build_parser(builder).extraneous(input)
+            }
+            AutoRadixState::Magnitude(parser) => parser.extraneous(input),
+        }
+    }
+
+    fn insufficient(&self) -> Self::Error {
+        ParseIntError::Empty
+    }
+
+    fn try_parse(&mut self, input: &'a str) -> Result<Poll<(Self::Output, &'a str)>, Self::Error> {
+        if let AutoRadixState::Prefix(builder) = &self.state {
+            let mut chars = input.chars();
+            let (radix_builder, remaining) = match chars.next() {
+                None => return Ok(Poll::Pending),
+                Some('0') => match chars.next() {
+                    // A lone trailing `0` is ambiguous (it could be the whole value, or the
+                    // start of a `0x`/`0o`/`0b` prefix that hasn't arrived yet). We resolve it
+                    // as the complete value rather than stay `Pending` forever on complete-input
+                    // callers; streaming callers that feed a prefix letter in a later chunk will
+                    // instead see it rejected as extraneous input.
+                    None => (builder.clone(), input),
+                    Some(c) if c.eq_ignore_ascii_case(&'x') => {
+                        (builder.with_radix(T::from(16)), chars.as_str())
+                    }
+                    Some(c) if c.eq_ignore_ascii_case(&'o') => {
+                        (builder.with_radix(T::from(8)), chars.as_str())
+                    }
+                    Some(c) if c.eq_ignore_ascii_case(&'b') => {
+                        (builder.with_radix(T::from(2)), chars.as_str())
+                    }
+                    Some(_) => (builder.clone(), input),
+                },
+                Some(_) => (builder.clone(), input),
+            };
+            self.state =
+                AutoRadixState::Magnitude(BuildParser::<&'a str>::build_parser(&radix_builder));
+            return self.try_parse(remaining);
+        }
+
+        let AutoRadixState::Magnitude(parser) = &mut self.state else {
+            unreachable!()
+        };
+        parser.try_parse(input)
+    }
+}
+
 /// Simple example to make sure it works.
 #[test]
 fn deadbeef() {
-    assert_eq!(ParseRadix(16).parse_one_only("deadbeef"), Ok(0xdeadbeef));
+    assert_eq!(ParseRadix::new(16u32).parse_one_only("deadbeef"), Ok(0xdeadbeef));
+}
+
+#[test]
+fn max_digits_cuts_off_value() {
+    assert_eq!(
+        ParseRadix::new(10).max_digits(2).parse_one("1234"),
+        Ok((12, "34"))
+    );
+}
+
+#[test]
+fn max_value_cuts_off_value() {
+    assert_eq!(
+        ParseRadix::new(10).max_value(65535).parse_one("655359999"),
+        Ok((65535, "9999"))
+    );
+}
+
+#[test]
+fn signed_parses_negative() {
+    assert_eq!(ParseRadix::new(10).signed().parse_one_only("-42"), Ok(-42));
+}
+
+#[test]
+fn signed_parses_positive() {
+    assert_eq!(ParseRadix::new(10).signed().parse_one_only("+42"), Ok(42));
+}
+
+#[test]
+fn signed_parses_unsigned() {
+    assert_eq!(ParseRadix::new(10).signed().parse_one_only("42"), Ok(42));
+}
+
+#[test]
+fn signed_pending_on_sign_alone() {
+    let mut parser = ParseRadix::new(10).signed().build_parser();
+    assert_eq!(parser.try_parse("-"), Ok(Poll::Pending));
+    assert_eq!(parser.try_parse("42"), Ok(Poll::Ready((-42, ""))));
+}
+
+#[test]
+fn auto_radix_detects_hex() {
+    assert_eq!(
+        ParseRadix::new(10u32).auto_radix().parse_one_only("0xdeadbeef"),
+        Ok(0xdeadbeef)
+    );
+}
+
+#[test]
+fn auto_radix_detects_octal_and_binary() {
+    assert_eq!(
+        ParseRadix::new(10).auto_radix().parse_one_only("0o17"),
+        Ok(15)
+    );
+    assert_eq!(
+        ParseRadix::new(10).auto_radix().parse_one_only("0b101"),
+        Ok(5)
+    );
+}
+
+#[test]
+fn auto_radix_falls_back_to_default() {
+    assert_eq!(
+        ParseRadix::new(10).auto_radix().parse_one_only("42"),
+        Ok(42)
+    );
+}
+
+#[test]
+fn auto_radix_parses_lone_zero() {
+    assert_eq!(ParseRadix::new(10).auto_radix().parse_one_only("0"), Ok(0));
+}
+
+#[test]
+fn auto_radix_pending_on_prefix_alone() {
+    let mut parser = ParseRadix::new(10).auto_radix().build_parser();
+    assert_eq!(parser.try_parse("0x"), Ok(Poll::Pending));
+    assert_eq!(parser.try_parse("ff"), Ok(Poll::Ready((0xff, ""))));
+}
+
+#[test]
+fn deadbeef_bytes() {
+    assert_eq!(
+        ParseRadix::new(16u32).parse_one_only(&b"deadbeef"[..]),
+        Ok(0xdeadbeef)
+    );
+}
+
+#[test]
+fn bytes_cut_off_at_non_digit() {
+    assert_eq!(
+        ParseRadix::new(10).parse_one(&b"123abc"[..]),
+        Ok((123, &b"abc"[..]))
+    );
 }
@@ -0,0 +1,89 @@
+use core::task::Poll;
+use parse_trait::{BuildParser, Parse};
+
+#[test]
+fn try_parse_recover_default_stops_at_first_error() {
+    let mut parser = DigitBuilder.build_parser();
+    let (output, errors, remaining) = parser.try_parse_recover("a");
+    assert_eq!(output, None);
+    assert_eq!(errors, vec![()]);
+    assert_eq!(remaining, "");
+}
+
+#[test]
+fn try_parse_recover_default_succeeds_without_error() {
+    let mut parser = DigitBuilder.build_parser();
+    let (output, errors, remaining) = parser.try_parse_recover("1a");
+    assert_eq!(output, Some(1));
+    assert!(errors.is_empty());
+    assert_eq!(remaining, "a");
+}
+
+#[test]
+fn recover_with_skips_to_next_comma() {
+    let mut parser = DigitBuilder.recover_with(SkipToCommaBuilder);
+    let (output, errors, remaining) = parser.try_parse_recover("x,2");
+    assert_eq!(output, Some(2));
+    assert_eq!(errors, vec![()]);
+    assert_eq!(remaining, "");
+}
+
+#[test]
+fn recover_with_gives_up_without_a_delimiter() {
+    let mut parser = DigitBuilder.recover_with(SkipToCommaBuilder);
+    let (output, errors, remaining) = parser.try_parse_recover("xyz");
+    assert_eq!(output, None);
+    assert_eq!(errors, vec![()]);
+    assert_eq!(remaining, "");
+}
+
+struct DigitBuilder;
+struct DigitParser;
+impl<'a> BuildParser<&'a str> for DigitBuilder {
+    type Parser = DigitParser;
+    fn build_parser(&self) -> DigitParser {
+        DigitParser
+    }
+}
+impl<'a> Parse<&'a str> for DigitParser {
+    type Output = u32;
+    type Error = ();
+
+    fn extraneous(&self, _input: &'a str) -> Self::Error {}
+    fn insufficient(&self) -> Self::Error {}
+
+    fn try_parse(&mut self, input: &'a str) -> Result<Poll<(u32, &'a str)>, ()> {
+        let mut chars = input.chars();
+        match chars.next() {
+            None => Ok(Poll::Pending),
+            Some(c) => match c.to_digit(10) {
+                Some(d) => Ok(Poll::Ready((d, chars.as_str()))),
+                None => Err(()),
+            },
+        }
+    }
+}
+
+/// Skips input up to and including the next comma, failing if none is found.
+struct SkipToCommaBuilder;
+struct SkipToCommaParser;
+impl<'a> BuildParser<&'a str> for SkipToCommaBuilder {
+    type Parser = SkipToCommaParser;
+    fn build_parser(&self) -> SkipToCommaParser {
+        SkipToCommaParser
+    }
+}
+impl<'a> Parse<&'a str> for SkipToCommaParser {
+    type Output = ();
+    type Error = ();
+
+    fn extraneous(&self, _input: &'a str) -> Self::Error {}
+    fn insufficient(&self) -> Self::Error {}
+
+    fn try_parse(&mut self, input: &'a str) -> Result<Poll<((), &'a str)>, ()> {
+        match input.split_once(',') {
+            Some((_, rest)) => Ok(Poll::Ready(((), rest))),
+            None => Err(()),
+        }
+    }
+}
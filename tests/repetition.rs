@@ -0,0 +1,95 @@
+use core::task::Poll;
+use parse_trait::{BuildParser, Parse};
+
+#[test]
+fn many_collects_zero_or_more() {
+    let (values, remaining): (Vec<u32>, _) = DigitBuilder.many().parse("abc").unwrap();
+    assert_eq!(values, Vec::<u32>::new());
+    assert_eq!(remaining, "abc");
+
+    let values: Vec<u32> = DigitBuilder.many().parse_only("123").unwrap();
+    assert_eq!(values, vec![1, 2, 3]);
+}
+
+#[test]
+fn many1_requires_at_least_one() {
+    assert!(DigitBuilder.many1::<Vec<u32>>().parse_only("").is_err());
+    let values: Vec<u32> = DigitBuilder.many1().parse_only("7").unwrap();
+    assert_eq!(values, vec![7]);
+}
+
+#[test]
+fn many_pending_then_resumes() {
+    let mut parser = DigitBuilder.many::<Vec<u32>>();
+    assert_eq!(parser.try_parse(""), Ok(Poll::Pending));
+    assert_eq!(parser.try_parse("12"), Ok(Poll::Ready((vec![1, 2], ""))));
+}
+
+#[test]
+fn separated_by_commas() {
+    let values: Vec<u32> = DigitBuilder
+        .separated_by(CommaBuilder)
+        .parse_only("1,2,3")
+        .unwrap();
+    assert_eq!(values, vec![1, 2, 3]);
+}
+
+#[test]
+fn separated_by_single_value() {
+    let values: Vec<u32> = DigitBuilder
+        .separated_by(CommaBuilder)
+        .parse_only("4")
+        .unwrap();
+    assert_eq!(values, vec![4]);
+}
+
+struct DigitBuilder;
+struct DigitParser;
+impl<'a> BuildParser<&'a str> for DigitBuilder {
+    type Parser = DigitParser;
+    fn build_parser(&self) -> DigitParser {
+        DigitParser
+    }
+}
+impl<'a> Parse<&'a str> for DigitParser {
+    type Output = u32;
+    type Error = ();
+
+    fn extraneous(&self, _input: &'a str) -> Self::Error {}
+    fn insufficient(&self) -> Self::Error {}
+
+    fn try_parse(&mut self, input: &'a str) -> Result<Poll<(u32, &'a str)>, ()> {
+        let mut chars = input.chars();
+        match chars.next() {
+            None => Ok(Poll::Pending),
+            Some(c) => match c.to_digit(10) {
+                Some(d) => Ok(Poll::Ready((d, chars.as_str()))),
+                None => Err(()),
+            },
+        }
+    }
+}
+
+struct CommaBuilder;
+struct CommaParser;
+impl<'a> BuildParser<&'a str> for CommaBuilder {
+    type Parser = CommaParser;
+    fn build_parser(&self) -> CommaParser {
+        CommaParser
+    }
+}
+impl<'a> Parse<&'a str> for CommaParser {
+    type Output = ();
+    type Error = ();
+
+    fn extraneous(&self, _input: &'a str) -> Self::Error {}
+    fn insufficient(&self) -> Self::Error {}
+
+    fn try_parse(&mut self, input: &'a str) -> Result<Poll<((), &'a str)>, ()> {
+        match input.strip_prefix(',') {
+            Some(rest) => Ok(Poll::Ready(((), rest))),
+            None if input.is_empty() => Ok(Poll::Pending),
+            None => Err(()),
+        }
+    }
+}
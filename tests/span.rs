@@ -0,0 +1,91 @@
+use core::task::Poll;
+use parse_trait::Parse;
+
+#[test]
+fn with_span_reports_consumed_range() {
+    let mut parser = Eq('a').with_span();
+    let ((c, span), remaining) = parser.parse("ab").unwrap();
+    assert_eq!(c, 'a');
+    assert_eq!(span, 0..1);
+    assert_eq!(remaining, "b");
+}
+
+#[test]
+fn with_span_restarts_per_wrapper_when_composed() {
+    // Each `with_span` tracks offsets relative to its own input, so composing two of them (rather
+    // than wrapping the outer `then`) does not yield absolute document offsets: both spans start
+    // from 0.
+    let mut parser = Eq('a').with_span().then(Eq('b').with_span());
+    let ((first, first_span), (second, second_span)) = parser.parse_only("ab").unwrap();
+    assert_eq!(first, 'a');
+    assert_eq!(first_span, 0..1);
+    assert_eq!(second, 'b');
+    assert_eq!(second_span, 0..1);
+}
+
+#[test]
+fn with_span_accumulates_across_streamed_calls() {
+    let mut parser = Eq('a').with_span();
+    assert_eq!(parser.try_parse(""), Ok(Poll::Pending));
+    let ((c, span), remaining) = parser.parse("a").unwrap();
+    assert_eq!(c, 'a');
+    assert_eq!(span, 0..1);
+    assert_eq!(remaining, "");
+}
+
+#[test]
+fn with_span_start_survives_a_nonempty_pending_chunk() {
+    let mut parser = Lit("ab").with_span();
+    assert_eq!(parser.try_parse("a"), Ok(Poll::Pending));
+    let ((_, span), remaining) = parser.parse("b").unwrap();
+    assert_eq!(span, 0..2);
+    assert_eq!(remaining, "");
+}
+
+struct Eq(char);
+impl<'a> Parse<&'a str> for Eq {
+    type Output = char;
+    type Error = String;
+    fn extraneous(&self, _input: &'a str) -> Self::Error {
+        "extraneous".into()
+    }
+    fn insufficient(&self) -> Self::Error {
+        "insufficient".into()
+    }
+    fn try_parse(&mut self, input: &'a str) -> Result<Poll<(char, &'a str)>, String> {
+        if input.is_empty() {
+            return Ok(Poll::Pending);
+        }
+        let mut chars = input.chars();
+        let c = chars.next().unwrap();
+        if c == self.0 {
+            Ok(Poll::Ready((c, chars.as_str())))
+        } else {
+            Err(format!("expected {}", self.0))
+        }
+    }
+}
+
+/// Matches a fixed multi-character literal, returning `Pending` while `input` is still a prefix
+/// of it (so callers can feed it one chunk at a time).
+struct Lit(&'static str);
+impl<'a> Parse<&'a str> for Lit {
+    type Output = ();
+    type Error = String;
+    fn extraneous(&self, _input: &'a str) -> Self::Error {
+        "extraneous".into()
+    }
+    fn insufficient(&self) -> Self::Error {
+        "insufficient".into()
+    }
+    fn try_parse(&mut self, input: &'a str) -> Result<Poll<((), &'a str)>, String> {
+        if let Some(remaining) = input.strip_prefix(self.0) {
+            Ok(Poll::Ready(((), remaining)))
+        } else if self.0.starts_with(input) {
+            self.0 = &self.0[input.len()..];
+            Ok(Poll::Pending)
+        } else {
+            Err(format!("expected {}", self.0))
+        }
+    }
+}